@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ArweaveError {
+    #[error("error fetching transaction info: {0}")]
+    TransactionInfoError(String),
+
+    #[error("network error: {0}")]
+    NetworkError(String),
+
+    #[error("gateway returned HTTP {status}")]
+    GatewayStatus {
+        status: u16,
+        retry_after: Option<Duration>,
+        body: String,
+    },
+
+    #[error("transaction submission rejected with HTTP {status}: {body}")]
+    SubmissionRejected { status: u16, body: String },
+
+    #[error("failed to serialize transaction for submission: {0}")]
+    SubmissionSerialization(String),
+
+    #[error("response exceeded the configured limit of {limit} bytes")]
+    PayloadTooLarge { limit: u64 },
+}
+
+impl ArweaveError {
+    /// Whether the error is worth retrying: a transient network failure or a
+    /// gateway response of 429 / 5xx.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ArweaveError::NetworkError(_) => true,
+            ArweaveError::GatewayStatus { status, .. } => {
+                *status == 429 || (500..600).contains(status)
+            }
+            _ => false,
+        }
+    }
+
+    /// The `Retry-After` delay advertised by the gateway, when present.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ArweaveError::GatewayStatus { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
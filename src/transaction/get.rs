@@ -1,6 +1,13 @@
-use pretend::{pretend, JsonResult, Pretend, resolver::UrlResolver, Url};
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::stream::{self, BoxStream, Stream, StreamExt, TryStreamExt};
+use pretend::Url;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use pretend_reqwest::Client as HttpClient;
 
 use crate::error::ArweaveError;
 
@@ -26,12 +33,11 @@ pub struct TransactionData {
     pub data_root: String,
 }
 
-#[allow(unused)]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct TransactionConfirmedData {
-    block_indep_hash: String,
-    block_height: usize,
-    number_of_confirmations: usize,
+    pub block_indep_hash: String,
+    pub block_height: usize,
+    pub number_of_confirmations: usize,
 }
 
 #[allow(unused)]
@@ -40,63 +46,565 @@ pub struct TransactionStatusResponse {
     status: usize,
     confirmed: Option<TransactionConfirmedData>,
 }
-#[pretend]
-trait TransactionInfoFetch {
-    #[request(method = "GET", path = "/price/{byte_size}")]
-    async fn tx_get_price(&self, byte_size: &str) -> pretend::Result<String>;
 
-    #[request(method = "GET", path = "/tx/{id}")]
-    async fn tx_get(&self, id: &str) -> pretend::Result<JsonResult<TransactionData, ArweaveError>>;
+/// The confirmation state of a transaction, derived from both the HTTP status
+/// code returned by `/tx/{id}/status` and the JSON payload.
+#[derive(Debug)]
+pub enum TxStatus {
+    /// Mined and included in the weave, with confirmation details.
+    Confirmed(TransactionConfirmedData),
+    /// Accepted but not yet mined (HTTP 202, or a 200 with no `confirmed` block).
+    Pending,
+    /// Unknown to the gateway (HTTP 404).
+    NotFound,
+    /// The gateway reported an unexpected, non-retryable outcome.
+    Failed(String),
+}
 
-    #[request(method = "GET", path = "/tx/{id}/status")]
-    async fn tx_status(&self, id: &str) -> pretend::Result<JsonResult<TransactionStatusResponse, ArweaveError>>;
+/// Transport used by [`TransactionInfoClient`] to talk to a gateway.
+///
+/// Abstracting the HTTP layer behind this trait lets callers unit-test code
+/// that depends on the client without opening a socket: ship a [`MockTransport`]
+/// instead of the reqwest-backed [`ReqwestTransport`] and assert which endpoints
+/// were hit.
+pub trait ArweaveTransport {
+    async fn get_json<T: DeserializeOwned>(&self, path: &str) -> Result<T, ArweaveError>;
+    async fn get_text(&self, path: &str) -> Result<String, ArweaveError>;
+    /// Fetch an endpoint without treating a non-2xx status as an error, returning
+    /// the raw `(status, body, retry_after)`. Used where the status code itself is
+    /// part of the response semantics (e.g. `/tx/{id}/status` returning 202 or 404);
+    /// the parsed `Retry-After` is surfaced so retries can honor it.
+    async fn get_raw(&self, path: &str) -> Result<(u16, String, Option<Duration>), ArweaveError>;
+    /// `POST` a JSON `body` to an endpoint, returning the raw `(status, body)`
+    /// without treating a non-2xx status as an error.
+    async fn post_raw(&self, path: &str, body: Vec<u8>) -> Result<(u16, String), ArweaveError>;
+    /// `GET` an endpoint as an incremental byte stream, returning the advertised
+    /// `Content-Length` when present alongside the stream.
+    async fn get_stream(
+        &self,
+        path: &str,
+    ) -> Result<(Option<u64>, BoxStream<'static, Result<Bytes, ArweaveError>>), ArweaveError>;
 }
 
-pub struct TransactionInfoClient(Pretend<HttpClient, UrlResolver>);
+/// reqwest-backed [`ArweaveTransport`] that issues real requests against a gateway.
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+    base_url: Url,
+}
 
-impl TransactionInfoClient {
-    pub fn new(url: Url) -> Self {
-        let client = HttpClient::default();
-        let pretend = Pretend::for_client(client).with_url(url);
-        Self(pretend)
+impl ReqwestTransport {
+    pub fn new(base_url: Url) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url,
+        }
     }
 
-    pub async fn get_price(&self, byte_size: &str) -> Result<String, ArweaveError> {
-        self.0.tx_get_price(byte_size)
+    fn endpoint(&self, path: &str) -> Result<Url, ArweaveError> {
+        self.base_url
+            .join(path.trim_start_matches('/'))
+            .map_err(|err| ArweaveError::TransactionInfoError(err.to_string()))
+    }
+
+    async fn fetch(&self, path: &str) -> Result<reqwest::Response, ArweaveError> {
+        let url = self.endpoint(path)?;
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|err| ArweaveError::NetworkError(err.to_string()))?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let retry_after = parse_retry_after(&response);
+        let body = response.text().await.unwrap_or_default();
+        Err(ArweaveError::GatewayStatus {
+            status: status.as_u16(),
+            retry_after,
+            body,
+        })
+    }
+}
+
+/// Parse a `Retry-After` header expressed in seconds, ignoring HTTP-date form.
+fn parse_retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+impl ArweaveTransport for ReqwestTransport {
+    async fn get_json<T: DeserializeOwned>(&self, path: &str) -> Result<T, ArweaveError> {
+        self.fetch(path)
+            .await?
+            .json::<T>()
             .await
-            .map_err(|err| 
-                ArweaveError::TransactionInfoError(err.to_string()))
+            .map_err(|err| ArweaveError::TransactionInfoError(err.to_string()))
     }
 
-    pub async fn get(&self, id: &str) -> Result<TransactionData, ArweaveError> {
-        self.0.tx_get(id)
+    async fn get_text(&self, path: &str) -> Result<String, ArweaveError> {
+        self.fetch(path)
+            .await?
+            .text()
             .await
-            .map(|op| match op {
-                JsonResult::Ok(op) => op,
-                JsonResult::Err(err) => panic!("Error parsing info {}", err),
-            })
-            .map_err(|op| ArweaveError::TransactionInfoError(op.to_string()))
+            .map_err(|err| ArweaveError::NetworkError(err.to_string()))
     }
 
-    pub async fn get_status(&self, id: &str) -> Result<TransactionStatusResponse, ArweaveError> {
-        let response = self.0.tx_status(id)
+    async fn get_raw(&self, path: &str) -> Result<(u16, String, Option<Duration>), ArweaveError> {
+        let url = self.endpoint(path)?;
+        let response = self
+            .client
+            .get(url)
+            .send()
             .await
-            .expect("Error getting tx status");
-        match response {
-            JsonResult::Ok(n) => Ok(n),
-            JsonResult::Err(_) => todo!(),
+            .map_err(|err| ArweaveError::NetworkError(err.to_string()))?;
+        let status = response.status().as_u16();
+        let retry_after = parse_retry_after(&response);
+        let body = response
+            .text()
+            .await
+            .map_err(|err| ArweaveError::NetworkError(err.to_string()))?;
+        Ok((status, body, retry_after))
+    }
+
+    async fn get_stream(
+        &self,
+        path: &str,
+    ) -> Result<(Option<u64>, BoxStream<'static, Result<Bytes, ArweaveError>>), ArweaveError> {
+        let url = self.endpoint(path)?;
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|err| ArweaveError::NetworkError(err.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let retry_after = parse_retry_after(&response);
+            let body = response.text().await.unwrap_or_default();
+            return Err(ArweaveError::GatewayStatus {
+                status: status.as_u16(),
+                retry_after,
+                body,
+            });
         }
+
+        let content_length = response.content_length();
+        let stream = response
+            .bytes_stream()
+            .map_err(|err| ArweaveError::NetworkError(err.to_string()));
+        Ok((content_length, stream.boxed()))
+    }
+
+    async fn post_raw(&self, path: &str, body: Vec<u8>) -> Result<(u16, String), ArweaveError> {
+        let url = self.endpoint(path)?;
+        let response = self
+            .client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await
+            .map_err(|err| ArweaveError::NetworkError(err.to_string()))?;
+        let status = response.status().as_u16();
+        let body = response
+            .text()
+            .await
+            .map_err(|err| ArweaveError::NetworkError(err.to_string()))?;
+        Ok((status, body))
     }
 }
 
+/// A single canned response handed out by [`MockTransport`].
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub body: String,
+    pub retry_after: Option<Duration>,
+}
+
+impl MockResponse {
+    pub fn new(body: impl Into<String>) -> Self {
+        Self {
+            status: 200,
+            body: body.into(),
+            retry_after: None,
+        }
+    }
+
+    /// A response carrying an explicit HTTP status, e.g. `429` or `503`.
+    pub fn with_status(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: body.into(),
+            retry_after: None,
+        }
+    }
+
+    /// Attach a `Retry-After` delay to this response.
+    pub fn retry_after(mut self, delay: Duration) -> Self {
+        self.retry_after = Some(delay);
+        self
+    }
+}
+
+/// In-memory [`ArweaveTransport`] for tests.
+///
+/// Queue responses with [`MockTransport::push`]; each call pops the next one and
+/// records the `(method, path)` that was requested so tests can assert exactly
+/// which endpoints were hit. An empty queue yields a [`ArweaveError::TransactionInfoError`].
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    responses: Arc<Mutex<VecDeque<MockResponse>>>,
+    requests: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a response to be returned by the next call.
+    pub fn push(&self, response: MockResponse) {
+        self.responses.lock().unwrap().push_back(response);
+    }
+
+    /// The `(method, path)` pairs recorded so far, in request order.
+    pub fn requests(&self) -> Vec<(String, String)> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Record the request and pop the next queued response without interpreting
+    /// its status code.
+    fn take(&self, method: &str, path: &str) -> Result<MockResponse, ArweaveError> {
+        self.requests
+            .lock()
+            .unwrap()
+            .push((method.to_string(), path.to_string()));
+        self.responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .ok_or_else(|| {
+                ArweaveError::TransactionInfoError(format!("no mock response queued for {}", path))
+            })
+    }
+
+    /// Like [`MockTransport::take`], mapping a non-2xx status to a gateway error.
+    fn record(&self, method: &str, path: &str) -> Result<MockResponse, ArweaveError> {
+        let response = self.take(method, path)?;
+        if (200..300).contains(&response.status) {
+            Ok(response)
+        } else {
+            Err(ArweaveError::GatewayStatus {
+                status: response.status,
+                retry_after: response.retry_after,
+                body: response.body,
+            })
+        }
+    }
+}
+
+impl ArweaveTransport for MockTransport {
+    async fn get_json<T: DeserializeOwned>(&self, path: &str) -> Result<T, ArweaveError> {
+        let response = self.record("GET", path)?;
+        serde_json::from_str(&response.body)
+            .map_err(|err| ArweaveError::TransactionInfoError(err.to_string()))
+    }
+
+    async fn get_text(&self, path: &str) -> Result<String, ArweaveError> {
+        Ok(self.record("GET", path)?.body)
+    }
+
+    async fn get_raw(&self, path: &str) -> Result<(u16, String, Option<Duration>), ArweaveError> {
+        let response = self.take("GET", path)?;
+        Ok((response.status, response.body, response.retry_after))
+    }
+
+    async fn post_raw(&self, path: &str, _body: Vec<u8>) -> Result<(u16, String), ArweaveError> {
+        let response = self.take("POST", path)?;
+        Ok((response.status, response.body))
+    }
+
+    async fn get_stream(
+        &self,
+        path: &str,
+    ) -> Result<(Option<u64>, BoxStream<'static, Result<Bytes, ArweaveError>>), ArweaveError> {
+        let response = self.take("GET", path)?;
+        if !(200..300).contains(&response.status) {
+            return Err(ArweaveError::GatewayStatus {
+                status: response.status,
+                retry_after: response.retry_after,
+                body: response.body,
+            });
+        }
+        let bytes = Bytes::from(response.body.into_bytes());
+        let content_length = Some(bytes.len() as u64);
+        let stream = stream::once(async move { Ok(bytes) }).boxed();
+        Ok((content_length, stream))
+    }
+}
+
+/// Retry policy for transient gateway failures.
+///
+/// A retryable outcome (connection error, HTTP 429, or 5xx) is retried after
+/// `min(max_delay, base_delay * 2^attempt)`, optionally with jitter, unless the
+/// response advertised a `Retry-After` which is honored instead.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    pub max_retries: usize,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub jitter: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// A policy that never retries, preserving fail-fast behavior.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// The exponential backoff for `attempt`, capped at `max_delay` and with
+    /// random jitter in `[0, delay / 2]` when enabled.
+    fn backoff(&self, attempt: usize) -> Duration {
+        let exponent = attempt.min(32) as u32;
+        let scaled = self
+            .base_delay
+            .checked_mul(2u32.saturating_pow(exponent))
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        if self.jitter {
+            let extra = rand::random::<f64>() * (scaled.as_secs_f64() / 2.0);
+            scaled + Duration::from_secs_f64(extra)
+        } else {
+            scaled
+        }
+    }
+}
+
+/// Bounds enforced while downloading transaction data.
+#[derive(Debug, Clone)]
+pub struct DownloadConfig {
+    /// Maximum number of bytes to read before aborting with
+    /// [`ArweaveError::PayloadTooLarge`].
+    pub max_bytes: u64,
+}
+
+impl Default for DownloadConfig {
+    fn default() -> Self {
+        Self {
+            max_bytes: 100 * 1024 * 1024,
+        }
+    }
+}
+
+pub struct TransactionInfoClient<T: ArweaveTransport = ReqwestTransport> {
+    transport: T,
+    retry: RetryConfig,
+}
+
+impl TransactionInfoClient<ReqwestTransport> {
+    pub fn new(url: Url) -> Self {
+        Self {
+            transport: ReqwestTransport::new(url),
+            retry: RetryConfig::none(),
+        }
+    }
+}
+
+impl<T: ArweaveTransport> TransactionInfoClient<T> {
+    /// Build a client over an arbitrary transport, e.g. a [`MockTransport`] in tests.
+    pub fn with_transport(transport: T) -> Self {
+        Self {
+            transport,
+            retry: RetryConfig::none(),
+        }
+    }
+
+    /// Enable automatic retry of transient failures using `config`.
+    pub fn with_retry(mut self, config: RetryConfig) -> Self {
+        self.retry = config;
+        self
+    }
+
+    /// Run `op`, retrying retryable failures per the configured [`RetryConfig`].
+    async fn with_retries<F, Fut, R>(&self, mut op: F) -> Result<R, ArweaveError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<R, ArweaveError>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match op().await {
+                Ok(value) => return Ok(value),
+                Err(err) => {
+                    if attempt >= self.retry.max_retries || !err.is_retryable() {
+                        return Err(err);
+                    }
+                    let delay = err.retry_after().unwrap_or_else(|| self.retry.backoff(attempt));
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    pub async fn get_price(&self, byte_size: &str) -> Result<String, ArweaveError> {
+        let path = format!("/price/{}", byte_size);
+        self.with_retries(|| self.transport.get_text(&path)).await
+    }
+
+    pub async fn get(&self, id: &str) -> Result<TransactionData, ArweaveError> {
+        let path = format!("/tx/{}", id);
+        self.with_retries(|| self.transport.get_json(&path)).await
+    }
+
+    /// Resolve the [`TxStatus`] of a transaction without panicking on unexpected
+    /// gateway responses.
+    pub async fn tx_status(&self, id: &str) -> Result<TxStatus, ArweaveError> {
+        self.with_retries(|| async {
+            let path = format!("/tx/{}/status", id);
+            let (status, body, retry_after) = self.transport.get_raw(&path).await?;
+            match status {
+                200 => {
+                    let parsed: TransactionStatusResponse = serde_json::from_str(&body)
+                        .map_err(|err| ArweaveError::TransactionInfoError(err.to_string()))?;
+                    Ok(match parsed.confirmed {
+                        Some(confirmed) => TxStatus::Confirmed(confirmed),
+                        None => TxStatus::Pending,
+                    })
+                }
+                202 => Ok(TxStatus::Pending),
+                404 => Ok(TxStatus::NotFound),
+                429 | 500..=599 => Err(ArweaveError::GatewayStatus {
+                    status,
+                    retry_after,
+                    body,
+                }),
+                other => Ok(TxStatus::Failed(format!(
+                    "unexpected status {}: {}",
+                    other, body
+                ))),
+            }
+        })
+        .await
+    }
+
+    /// Poll [`tx_status`](Self::tx_status) until the transaction reaches
+    /// `min_confirmations` or `timeout` elapses, returning the final status.
+    pub async fn wait_for_confirmation(
+        &self,
+        id: &str,
+        min_confirmations: usize,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<TxStatus, ArweaveError> {
+        let start = std::time::Instant::now();
+        loop {
+            let status = self.tx_status(id).await?;
+            if let TxStatus::Confirmed(ref confirmed) = status {
+                if confirmed.number_of_confirmations >= min_confirmations {
+                    return Ok(status);
+                }
+            }
+            if start.elapsed() >= timeout {
+                return Ok(status);
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// Stream the raw data of a transaction from `/tx/{id}/data` incrementally,
+    /// aborting with [`ArweaveError::PayloadTooLarge`] if the payload exceeds
+    /// `config.max_bytes`. The advertised `Content-Length` is checked up front
+    /// when present; otherwise the running byte count is enforced while reading.
+    pub async fn get_data_stream(
+        &self,
+        id: &str,
+        config: DownloadConfig,
+    ) -> impl Stream<Item = Result<Bytes, ArweaveError>> {
+        let path = format!("/tx/{}/data", id);
+        let (content_length, inner) = match self.transport.get_stream(&path).await {
+            Ok(parts) => parts,
+            Err(err) => return stream::once(async move { Err(err) }).boxed(),
+        };
+
+        let max = config.max_bytes;
+        if matches!(content_length, Some(len) if len > max) {
+            return stream::once(async move { Err(ArweaveError::PayloadTooLarge { limit: max }) })
+                .boxed();
+        }
+
+        stream::try_unfold((inner, 0u64), move |(mut inner, mut count)| async move {
+            match inner.next().await {
+                None => Ok(None),
+                Some(Err(err)) => Err(err),
+                Some(Ok(chunk)) => {
+                    count += chunk.len() as u64;
+                    if count > max {
+                        Err(ArweaveError::PayloadTooLarge { limit: max })
+                    } else {
+                        Ok(Some((chunk, (inner, count))))
+                    }
+                }
+            }
+        })
+        .boxed()
+    }
+
+    /// Convenience wrapper that collects [`get_data_stream`](Self::get_data_stream)
+    /// into a `Vec<u8>` under the same limit.
+    pub async fn get_data(
+        &self,
+        id: &str,
+        config: DownloadConfig,
+    ) -> Result<Vec<u8>, ArweaveError> {
+        let mut stream = Box::pin(self.get_data_stream(id, config).await);
+        let mut out = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            out.extend_from_slice(&chunk?);
+        }
+        Ok(out)
+    }
+}
 
 #[cfg(test)]
 mod tests {
-    use httpmock::{MockServer, Method::GET};
+    use httpmock::{Method::GET, MockServer};
     use pretend::Url;
     use tokio_test::block_on;
 
-    use crate::transaction::get::{TransactionInfoClient, TransactionData, Tag, TransactionStatusResponse, TransactionConfirmedData};
+    use std::time::Duration;
+
+    use crate::transaction::get::{
+        DownloadConfig, MockResponse, MockTransport, RetryConfig, Tag, TransactionConfirmedData,
+        TransactionData, TransactionInfoClient, TransactionStatusResponse, TxStatus,
+    };
 
     #[test]
     fn test_price() {
@@ -126,9 +634,9 @@ mod tests {
             id: id.to_string(),
             last_tx: "last_tx".to_string(),
             owner: "owner".to_string(),
-            tags: vec![ Tag { 
+            tags: vec![Tag {
                 name: "name".to_string(),
-                value: "value".to_string() 
+                value: "value".to_string(),
             }],
             target: "target".to_string(),
             quantity: "quantity".to_string(),
@@ -147,7 +655,7 @@ mod tests {
                 .header("Content-Type", "application/json")
                 .body(serde_json::to_string(&tx_info_mock).unwrap());
         });
-        
+
         let url = Url::parse(&server_url).unwrap();
         let client = TransactionInfoClient::new(url);
         let tx_info = block_on(client.get(id)).unwrap();
@@ -177,12 +685,161 @@ mod tests {
                 .header("Content-Type", "application/json")
                 .body(serde_json::to_string(&tx_status_mock).unwrap());
         });
-        
+
         let url = Url::parse(&server_url).unwrap();
         let client = TransactionInfoClient::new(url);
-        let tx_info = block_on(client.get_status(id)).unwrap();
+        let status = block_on(client.tx_status(id)).unwrap();
 
         mock.assert();
-        assert_eq!(tx_info.status, 1);
+        match status {
+            TxStatus::Confirmed(confirmed) => assert_eq!(confirmed.number_of_confirmations, 10),
+            other => panic!("expected confirmed status, got {:?}", other),
+        }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_tx_status_pending_and_not_found() {
+        let transport = MockTransport::new();
+        transport.push(MockResponse::with_status(202, "Pending"));
+        transport.push(MockResponse::with_status(404, "Not Found"));
+
+        let client = TransactionInfoClient::with_transport(transport);
+        assert!(matches!(block_on(client.tx_status("id")).unwrap(), TxStatus::Pending));
+        assert!(matches!(block_on(client.tx_status("id")).unwrap(), TxStatus::NotFound));
+    }
+
+    #[test]
+    fn test_wait_for_confirmation_polls_until_confirmed() {
+        let confirmed = TransactionStatusResponse {
+            status: 1,
+            confirmed: Some(TransactionConfirmedData {
+                block_indep_hash: "hash".to_string(),
+                block_height: 10,
+                number_of_confirmations: 5,
+            }),
+        };
+
+        let transport = MockTransport::new();
+        transport.push(MockResponse::with_status(202, "Pending"));
+        transport.push(MockResponse::new(serde_json::to_string(&confirmed).unwrap()));
+
+        let client = TransactionInfoClient::with_transport(transport.clone());
+        let status = block_on(client.wait_for_confirmation(
+            "id",
+            3,
+            Duration::from_millis(1),
+            Duration::from_secs(5),
+        ))
+        .unwrap();
+
+        assert!(matches!(status, TxStatus::Confirmed(_)));
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_get_with_mock_transport() {
+        let id = "arweave_tx_id";
+        let tx_info_mock = TransactionData {
+            format: 2,
+            id: id.to_string(),
+            last_tx: "last_tx".to_string(),
+            owner: "owner".to_string(),
+            tags: vec![],
+            target: "target".to_string(),
+            quantity: "quantity".to_string(),
+            data: vec![],
+            reward: "reward".to_string(),
+            signature: "signature".to_owned(),
+            data_size: "data_size".to_string(),
+            data_root: "data_root".to_owned(),
+        };
+
+        let transport = MockTransport::new();
+        transport.push(MockResponse::new(serde_json::to_string(&tx_info_mock).unwrap()));
+
+        let client = TransactionInfoClient::with_transport(transport.clone());
+        let tx_info = block_on(client.get(id)).unwrap();
+
+        assert_eq!(tx_info.id, "arweave_tx_id");
+        assert_eq!(transport.requests(), vec![("GET".to_string(), format!("/tx/{}", id))]);
+    }
+
+    #[test]
+    fn test_mock_transport_errors_when_queue_empty() {
+        let transport = MockTransport::new();
+        let client = TransactionInfoClient::with_transport(transport);
+        assert!(block_on(client.get("missing")).is_err());
+    }
+
+    #[test]
+    fn test_retry_recovers_from_transient_5xx() {
+        let id = "arweave_tx_id";
+        let tx_info_mock = TransactionData {
+            format: 2,
+            id: id.to_string(),
+            last_tx: "last_tx".to_string(),
+            owner: "owner".to_string(),
+            tags: vec![],
+            target: "target".to_string(),
+            quantity: "quantity".to_string(),
+            data: vec![],
+            reward: "reward".to_string(),
+            signature: "signature".to_owned(),
+            data_size: "data_size".to_string(),
+            data_root: "data_root".to_owned(),
+        };
+
+        let transport = MockTransport::new();
+        transport.push(MockResponse::with_status(503, "unavailable"));
+        transport.push(MockResponse::new(serde_json::to_string(&tx_info_mock).unwrap()));
+
+        let client = TransactionInfoClient::with_transport(transport.clone()).with_retry(RetryConfig {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            jitter: false,
+        });
+        let tx_info = block_on(client.get(id)).unwrap();
+
+        assert_eq!(tx_info.id, "arweave_tx_id");
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_retry_gives_up_after_max_retries() {
+        let transport = MockTransport::new();
+        transport.push(MockResponse::with_status(429, "slow down"));
+        transport.push(MockResponse::with_status(429, "slow down"));
+
+        let client = TransactionInfoClient::with_transport(transport.clone()).with_retry(RetryConfig {
+            max_retries: 1,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            jitter: false,
+        });
+        assert!(block_on(client.get("id")).is_err());
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_get_data_collects_stream() {
+        let transport = MockTransport::new();
+        transport.push(MockResponse::new("hello world"));
+
+        let client = TransactionInfoClient::with_transport(transport.clone());
+        let data = block_on(client.get_data("id", DownloadConfig::default())).unwrap();
+
+        assert_eq!(data, b"hello world");
+        assert_eq!(transport.requests(), vec![("GET".to_string(), "/tx/id/data".to_string())]);
+    }
+
+    #[test]
+    fn test_get_data_aborts_over_limit() {
+        let transport = MockTransport::new();
+        transport.push(MockResponse::new("this body is too large"));
+
+        let client = TransactionInfoClient::with_transport(transport);
+        let err = block_on(client.get_data("id", DownloadConfig { max_bytes: 4 })).unwrap_err();
+        assert!(matches!(err, super::ArweaveError::PayloadTooLarge { limit: 4 }));
+    }
+}
@@ -0,0 +1,292 @@
+use pretend::Url;
+use serde::Serialize;
+
+use crate::error::ArweaveError;
+use crate::transaction::get::{ArweaveTransport, ReqwestTransport, TransactionData};
+
+/// Maximum `data` size (bytes) a format-2 transaction may carry inline in a
+/// `POST /tx` body before the payload must be uploaded via `/chunk`.
+pub const MAX_INLINE_DATA_SIZE: usize = 12 * 1024;
+
+/// Size of a single chunk posted to `/chunk`.
+pub const CHUNK_SIZE: usize = 256 * 1024;
+
+/// A single chunk uploaded to `/chunk` for a format-2 transaction.
+///
+/// **Testing-only shape.** A real gateway requires the per-chunk Merkle proof
+/// (`data_path`) and expects `data_root` and `chunk` as base64url strings; this
+/// struct models neither — `chunk` serializes as a raw JSON byte array — so it
+/// exercises the resumable-upload control flow against a [`MockTransport`] but is
+/// not accepted by a live Arweave node. Modelling `data_path` and base64url
+/// encoding is left for when a chunk-proof builder lands in the crate.
+///
+/// [`MockTransport`]: crate::transaction::get::MockTransport
+#[derive(Serialize, Debug)]
+pub struct Chunk {
+    pub data_root: String,
+    pub data_size: String,
+    pub offset: String,
+    pub chunk: Vec<u8>,
+}
+
+/// Posts transactions and uploads their data, adjacent to
+/// [`TransactionInfoClient`](crate::transaction::get::TransactionInfoClient).
+pub struct TransactionSubmitClient<T: ArweaveTransport = ReqwestTransport> {
+    transport: T,
+}
+
+impl TransactionSubmitClient<ReqwestTransport> {
+    pub fn new(url: Url) -> Self {
+        Self {
+            transport: ReqwestTransport::new(url),
+        }
+    }
+}
+
+impl<T: ArweaveTransport> TransactionSubmitClient<T> {
+    /// Build a client over an arbitrary transport, e.g. a `MockTransport` in tests.
+    pub fn with_transport(transport: T) -> Self {
+        Self { transport }
+    }
+
+    /// Escape hatch for endpoints not yet modeled: `POST body` to `path` and
+    /// return the response body, mapping a non-2xx status to
+    /// [`ArweaveError::SubmissionRejected`].
+    pub async fn send_request_raw(&self, path: &str, body: String) -> Result<String, ArweaveError> {
+        let (status, response) = self.transport.post_raw(path, body.into_bytes()).await?;
+        if (200..300).contains(&status) {
+            Ok(response)
+        } else {
+            Err(ArweaveError::SubmissionRejected {
+                status,
+                body: response,
+            })
+        }
+    }
+
+    /// Submit a transaction header via `POST /tx`.
+    pub async fn post_transaction(&self, tx: &TransactionData) -> Result<(), ArweaveError> {
+        let body = serde_json::to_string(tx)
+            .map_err(|err| ArweaveError::SubmissionSerialization(err.to_string()))?;
+        self.send_request_raw("/tx", body).await.map(|_| ())
+    }
+
+    /// Submit a transaction header via `POST /tx` with the inline `data` omitted.
+    ///
+    /// A chunked v2 submission describes its payload via `data_root`/`data_size`
+    /// and delivers the bytes only through `/chunk`, so the header must not carry
+    /// the full inline `data` it is meant to offload.
+    async fn post_transaction_header(&self, tx: &TransactionData) -> Result<(), ArweaveError> {
+        let mut value = serde_json::to_value(tx)
+            .map_err(|err| ArweaveError::SubmissionSerialization(err.to_string()))?;
+        if let Some(object) = value.as_object_mut() {
+            object.insert("data".to_string(), serde_json::Value::Array(Vec::new()));
+        }
+        let body = serde_json::to_string(&value)
+            .map_err(|err| ArweaveError::SubmissionSerialization(err.to_string()))?;
+        self.send_request_raw("/tx", body).await.map(|_| ())
+    }
+
+    /// Submit `tx`, uploading its `data` in chunks when it is a format-2
+    /// transaction whose payload exceeds [`MAX_INLINE_DATA_SIZE`].
+    ///
+    /// On a chunked submission the upload is resumable: if a chunk fails the
+    /// returned [`SubmitError`] carries the byte offset acknowledged so far, so a
+    /// retry can resume via [`ChunkUploader::resume_from`] instead of re-uploading
+    /// from byte 0.
+    pub async fn submit(&self, tx: &TransactionData) -> Result<(), SubmitError> {
+        if tx.format >= 2 && tx.data.len() > MAX_INLINE_DATA_SIZE {
+            self.post_transaction_header(tx)
+                .await
+                .map_err(|source| SubmitError::new(source, 0))?;
+            let mut uploader = ChunkUploader::new(self, tx);
+            uploader
+                .upload()
+                .await
+                .map_err(|source| SubmitError::new(source, uploader.acknowledged()))?;
+        } else {
+            self.post_transaction(tx)
+                .await
+                .map_err(|source| SubmitError::new(source, 0))?;
+        }
+        Ok(())
+    }
+}
+
+/// Failure of [`TransactionSubmitClient::submit`] carrying the byte offset
+/// acknowledged before the error, so a retry can resume from there rather than
+/// re-uploading the whole payload.
+#[derive(Debug)]
+pub struct SubmitError {
+    /// The underlying cause.
+    pub source: ArweaveError,
+    /// Bytes of `data` the gateway acknowledged before the failure; `0` when the
+    /// transaction header itself failed to post.
+    pub acknowledged: usize,
+}
+
+impl SubmitError {
+    fn new(source: ArweaveError, acknowledged: usize) -> Self {
+        Self {
+            source,
+            acknowledged,
+        }
+    }
+}
+
+impl std::fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (acknowledged {} bytes)",
+            self.source, self.acknowledged
+        )
+    }
+}
+
+impl std::error::Error for SubmitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Resumable chunk uploader.
+///
+/// Tracks the byte offset acknowledged so far; on a failed chunk the offset is
+/// left at the last success, so calling [`ChunkUploader::upload`] again — or
+/// reconstructing with [`ChunkUploader::resume_from`] — continues from there
+/// instead of re-uploading the whole payload.
+///
+/// The posted [`Chunk`] body is a testing-only shape — see its documentation;
+/// a live gateway requires a `data_path` proof and base64url encoding this
+/// uploader does not yet produce.
+pub struct ChunkUploader<'a, T: ArweaveTransport> {
+    client: &'a TransactionSubmitClient<T>,
+    tx: &'a TransactionData,
+    acknowledged: usize,
+}
+
+impl<'a, T: ArweaveTransport> ChunkUploader<'a, T> {
+    pub fn new(client: &'a TransactionSubmitClient<T>, tx: &'a TransactionData) -> Self {
+        Self {
+            client,
+            tx,
+            acknowledged: 0,
+        }
+    }
+
+    /// Resume an interrupted upload from `offset`.
+    pub fn resume_from(
+        client: &'a TransactionSubmitClient<T>,
+        tx: &'a TransactionData,
+        offset: usize,
+    ) -> Self {
+        Self {
+            client,
+            tx,
+            acknowledged: offset,
+        }
+    }
+
+    /// The byte offset acknowledged by the gateway so far.
+    pub fn acknowledged(&self) -> usize {
+        self.acknowledged
+    }
+
+    /// Upload the remaining chunks, advancing [`acknowledged`](Self::acknowledged)
+    /// after each one the gateway accepts.
+    pub async fn upload(&mut self) -> Result<(), ArweaveError> {
+        let data = &self.tx.data;
+        while self.acknowledged < data.len() {
+            let end = (self.acknowledged + CHUNK_SIZE).min(data.len());
+            let chunk = Chunk {
+                data_root: self.tx.data_root.clone(),
+                data_size: self.tx.data_size.clone(),
+                offset: self.acknowledged.to_string(),
+                chunk: data[self.acknowledged..end].to_vec(),
+            };
+            let body = serde_json::to_string(&chunk)
+                .map_err(|err| ArweaveError::SubmissionSerialization(err.to_string()))?;
+            self.client.send_request_raw("/chunk", body).await?;
+            self.acknowledged = end;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio_test::block_on;
+
+    use crate::transaction::get::{MockResponse, MockTransport, TransactionData};
+    use crate::transaction::submit::{ChunkUploader, TransactionSubmitClient, CHUNK_SIZE};
+
+    fn tx_with_data(data: Vec<u8>) -> TransactionData {
+        TransactionData {
+            format: 2,
+            id: "id".to_string(),
+            last_tx: "last_tx".to_string(),
+            owner: "owner".to_string(),
+            tags: vec![],
+            target: "target".to_string(),
+            quantity: "quantity".to_string(),
+            data,
+            reward: "reward".to_string(),
+            signature: "signature".to_string(),
+            data_size: "data_size".to_string(),
+            data_root: "data_root".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_post_transaction_hits_tx_endpoint() {
+        let transport = MockTransport::new();
+        transport.push(MockResponse::new("OK"));
+
+        let client = TransactionSubmitClient::with_transport(transport.clone());
+        block_on(client.post_transaction(&tx_with_data(vec![]))).unwrap();
+
+        assert_eq!(transport.requests(), vec![("POST".to_string(), "/tx".to_string())]);
+    }
+
+    #[test]
+    fn test_submission_rejected_surfaces_status_and_body() {
+        let transport = MockTransport::new();
+        transport.push(MockResponse::with_status(400, "invalid tx"));
+
+        let client = TransactionSubmitClient::with_transport(transport);
+        let err = block_on(client.post_transaction(&tx_with_data(vec![]))).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::error::ArweaveError::SubmissionRejected { status: 400, .. }
+        ));
+    }
+
+    #[test]
+    fn test_chunk_upload_resumes_from_last_acknowledged() {
+        let data = vec![0u8; CHUNK_SIZE + CHUNK_SIZE / 2];
+        let tx = tx_with_data(data);
+
+        // First chunk succeeds, second fails: acknowledged advances by one chunk.
+        let transport = MockTransport::new();
+        transport.push(MockResponse::new("first ok"));
+        transport.push(MockResponse::with_status(503, "unavailable"));
+
+        let client = TransactionSubmitClient::with_transport(transport.clone());
+        let mut uploader = ChunkUploader::new(&client, &tx);
+        assert!(block_on(uploader.upload()).is_err());
+        assert_eq!(uploader.acknowledged(), CHUNK_SIZE);
+
+        // Retrying posts only the remaining chunk.
+        transport.push(MockResponse::new("second ok"));
+        block_on(uploader.upload()).unwrap();
+        assert_eq!(uploader.acknowledged(), CHUNK_SIZE + CHUNK_SIZE / 2);
+
+        let posts: Vec<_> = transport
+            .requests()
+            .into_iter()
+            .filter(|(method, _)| method == "POST")
+            .collect();
+        assert_eq!(posts.len(), 3);
+    }
+}